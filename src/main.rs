@@ -1,31 +1,91 @@
 use cached::proc_macro::cached;
 use itertools::Itertools;
+use std::cmp::Reverse;
 use std::collections::BTreeMap;
+use std::collections::BinaryHeap;
 use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::fmt;
 use std::io::{self, Write};
 
-const ROWS: usize = 7;
-const COLS: usize = 7;
-const MIMIC_INITIAL_ROW: usize = 4;
-const MIMIC_INITIAL_COL: usize = 4;
-const MAX_BLOCKS_TO_REMOVE: usize = 10;
+/// Runtime board shape: dimensions, mimic start, and the removal budget, all
+/// configurable instead of baked in as `const`s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct BoardConfig {
+    rows: u8,
+    cols: u8,
+    mimic_row: u8,
+    mimic_col: u8,
+    max_removals: usize,
+}
+
+impl Default for BoardConfig {
+    fn default() -> Self {
+        BoardConfig {
+            rows: 7,
+            cols: 7,
+            mimic_row: 4,
+            mimic_col: 4,
+            max_removals: 10,
+        }
+    }
+}
+
+/// Bit index of cell `(row, col)` (`row` in `1..=rows`, `col` in `1..=cols`) within a board bitboard.
+fn bit_index(row: u8, col: u8, cols: u8) -> u32 {
+    (row - 1) as u32 * cols as u32 + (col - 1) as u32
+}
+
+/// Single-bit mask for cell `(row, col)`.
+fn cell_bit(row: u8, col: u8, cols: u8) -> u64 {
+    1u64 << bit_index(row, col, cols)
+}
 
-#[cached(size = 81)]
-fn is_valid_location(loc: (u8, u8)) -> bool {
+/// Mask with every cell of the configured board set.
+///
+/// Panics if `rows * cols` would not fit in the `u64` bitboard (63 usable cells, since
+/// `full_mask` needs one extra bit of headroom for the `<< … - 1` trick).
+fn full_mask(config: BoardConfig) -> u64 {
+    let cells = config.rows as u32 * config.cols as u32;
+    assert!(
+        cells <= 63,
+        "board of {}x{} ({} cells) does not fit in a 63-bit bitboard",
+        config.rows,
+        config.cols,
+        cells
+    );
+    (1u64 << cells) - 1
+}
+
+#[cached(size = 256)]
+fn is_valid_location(loc: (u8, u8), config: BoardConfig) -> bool {
     match loc {
-        (row, col) => row > 0 && row < 8 && col > 0 && col < 8,
+        (row, col) => row > 0 && row <= config.rows && col > 0 && col <= config.cols,
     }
 }
 
-#[cached(size = 49)]
-fn is_outer(row: u8, col: u8) -> bool {
-    row % 6 == 1 || col % 6 == 1
+/// A cell is outer iff it sits on the first/last row or column of the configured board.
+#[cached(size = 256)]
+fn is_outer(row: u8, col: u8, config: BoardConfig) -> bool {
+    row == 1 || row == config.rows || col == 1 || col == config.cols
+}
+
+/// Mask of outer cells, memoized per config.
+#[cached(size = 32)]
+fn outer_mask(config: BoardConfig) -> u64 {
+    let mut mask = 0u64;
+    for row in 1..=config.rows {
+        for col in 1..=config.cols {
+            if is_outer(row, col, config) {
+                mask |= cell_bit(row, col, config.cols);
+            }
+        }
+    }
+    mask
 }
 
-#[cached(size = 49)]
-fn get_neighbors(row: u8, col: u8) -> Vec<Cell> {
+#[cached(size = 256)]
+fn get_neighbors(row: u8, col: u8, config: BoardConfig) -> Vec<Cell> {
     let locations: [(u8, u8); 6] = if col % 2 == 0 {
         [
             (row - 1, col),
@@ -47,7 +107,7 @@ fn get_neighbors(row: u8, col: u8) -> Vec<Cell> {
     };
     let valid_locations: Vec<(u8, u8)> = locations
         .iter()
-        .filter(|&&loc| is_valid_location(loc))
+        .filter(|&&loc| is_valid_location(loc, config))
         .cloned()
         .collect();
 
@@ -57,6 +117,110 @@ fn get_neighbors(row: u8, col: u8) -> Vec<Cell> {
         .collect()
 }
 
+/// Mask of the on-board neighbors of `(row, col)`, live or not, built from `get_neighbors`.
+#[cached(size = 256)]
+fn neighbor_mask(row: u8, col: u8, config: BoardConfig) -> u64 {
+    get_neighbors(row, col, config)
+        .iter()
+        .fold(0u64, |mask, cell| mask | cell_bit(cell.row, cell.col, config.cols))
+}
+
+/// Decodes every set bit of `mask` back into its `Cell`.
+fn cells_from_mask(mask: u64, cols: u8) -> Vec<Cell> {
+    let mut cells = Vec::new();
+    let mut remaining = mask;
+    while remaining != 0 {
+        let index = remaining.trailing_zeros();
+        remaining &= remaining - 1;
+        cells.push(Cell {
+            row: (index / cols as u32) as u8 + 1,
+            col: (index % cols as u32) as u8 + 1,
+        });
+    }
+    cells
+}
+
+/// Mask of every cell in `cells`.
+fn mask_from_cells(cells: &[Cell], cols: u8) -> u64 {
+    cells
+        .iter()
+        .fold(0u64, |mask, cell| mask | cell_bit(cell.row, cell.col, cols))
+}
+
+/// Deterministic splitmix64 PRNG so `solve_annealing` runs are reproducible from a seed.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn gen_range(&mut self, upper: usize) -> usize {
+        (self.next_u64() % upper as u64) as usize
+    }
+}
+
+/// A single annealing move: add a cell, drop a cell, or swap one cell for another not in the set.
+fn propose_neighbor(current: &[Cell], available_blocks: &[Cell], rng: &mut Rng) -> Vec<Cell> {
+    let outside: Vec<Cell> = available_blocks
+        .iter()
+        .filter(|cell| !current.contains(cell))
+        .cloned()
+        .collect();
+
+    let can_add = !outside.is_empty() && current.len() < available_blocks.len();
+    let can_drop = !current.is_empty();
+
+    let mut moves: Vec<u8> = Vec::new();
+    if can_add {
+        moves.push(0);
+    }
+    if can_drop {
+        moves.push(1);
+    }
+    if can_add && can_drop {
+        moves.push(2);
+    }
+
+    let mut neighbor = current.to_vec();
+    match moves[rng.gen_range(moves.len())] {
+        0 => neighbor.push(outside[rng.gen_range(outside.len())]),
+        1 => {
+            let index = rng.gen_range(neighbor.len());
+            neighbor.remove(index);
+        }
+        _ => {
+            let index = rng.gen_range(neighbor.len());
+            neighbor[index] = outside[rng.gen_range(outside.len())];
+        }
+    }
+    neighbor
+}
+
+/// Walks `came_from` back from `end` to the Dijkstra source, returning the path in source-to-end order.
+fn reconstruct_path(came_from: &HashMap<Cell, Cell>, end: Cell) -> Vec<Cell> {
+    let mut path = vec![end];
+    let mut current = end;
+    while let Some(&previous) = came_from.get(&current) {
+        path.push(previous);
+        current = previous;
+    }
+    path.reverse();
+    path
+}
+
 #[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
 struct Cell {
     row: u8,
@@ -64,12 +228,12 @@ struct Cell {
 }
 
 impl Cell {
-    fn is_outer(&self) -> bool {
-        is_outer(self.row, self.col)
+    fn is_outer(&self, config: BoardConfig) -> bool {
+        is_outer(self.row, self.col, config)
     }
 
-    fn get_neighbors(&self) -> Vec<Cell> {
-        get_neighbors(self.row, self.col)
+    fn get_neighbors(&self, config: BoardConfig) -> Vec<Cell> {
+        get_neighbors(self.row, self.col, config)
     }
 }
 
@@ -80,56 +244,250 @@ impl fmt::Display for Cell {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
 struct Board {
-    cells: [[bool; COLS]; ROWS],
-    map_live_outer_cells: HashMap<(u8, u8), Cell>,
+    bits: u64,
+    config: BoardConfig,
 }
 
-impl Board {
-    fn new() -> Self {
-        Board {
-            cells: [[true; COLS]; ROWS],
-            ..Default::default()
+/// Mask of cells reachable by the mimic from its starting cell, memoized on the board bits and config.
+#[cached(size = 10000)]
+fn get_reachable_mask(bits: u64, config: BoardConfig) -> u64 {
+    let start = cell_bit(config.mimic_row, config.mimic_col, config.cols);
+    let outer = outer_mask(config);
+
+    let mut visited: u64 = 0;
+    let mut queue: VecDeque<u64> = VecDeque::new();
+    queue.push_back(start);
+
+    while let Some(cell_mask) = queue.pop_front() {
+        if visited & cell_mask != 0 {
+            continue;
+        }
+        visited |= cell_mask;
+
+        let index = cell_mask.trailing_zeros();
+        let row = (index / config.cols as u32) as u8 + 1;
+        let col = (index % config.cols as u32) as u8 + 1;
+        let cell_is_outer = cell_mask & outer != 0;
+
+        let mut neighbors = neighbor_mask(row, col, config) & bits;
+        while neighbors != 0 {
+            let neighbor_index = neighbors.trailing_zeros();
+            let neighbor_bit = 1u64 << neighbor_index;
+            neighbors &= neighbors - 1;
+
+            if visited & neighbor_bit != 0 {
+                continue;
+            }
+            if cell_is_outer && neighbor_bit & outer != 0 {
+                continue;
+            }
+
+            queue.push_back(neighbor_bit);
         }
     }
 
-    fn drop_cell(&mut self, row: u8, col: u8) {
-        self.cells[(row - 1) as usize][(col - 1) as usize] = false;
-        if is_outer(row, col) {
-            self.map_live_outer_cells.remove(&(row, col));
+    visited
+}
+
+/// Benefit of removing `removing_mask` from `bits`, memoized on those bitmasks and the config.
+/// Returns `(-1, 0)` when the removal would exceed `config.max_removals`.
+#[cached(size = 10000)]
+fn calc_benefit_bits(bits: u64, removing_mask: u64, config: BoardConfig) -> (isize, u64) {
+    let mut imaginary_board = Board { bits, config }
+        .create_imagine_board(&cells_from_mask(removing_mask, config.cols));
+    imaginary_board.remove_redundant_blocks();
+
+    let reachable_mask = get_reachable_mask(imaginary_board.bits, config);
+    let border_mask = reachable_mask & outer_mask(config);
+
+    let num_total_removing_cells =
+        border_mask.count_ones() as usize + removing_mask.count_ones() as usize;
+    if num_total_removing_cells > config.max_removals {
+        return (-1, 0);
+    }
+
+    let total_removing_mask = border_mask | removing_mask;
+    let benefit = reachable_mask.count_ones() as isize - border_mask.count_ones() as isize;
+    (benefit, total_removing_mask)
+}
+
+fn is_live(bits: u64, cell: Cell, cols: u8) -> bool {
+    bits & cell_bit(cell.row, cell.col, cols) != 0
+}
+
+/// Interior, non-outer cells still standing, excluding `exclude` (the mimic's cell).
+fn available_blocks_excluding(bits: u64, exclude: Cell, config: BoardConfig) -> Vec<Cell> {
+    let mut blocks = Vec::new();
+    for row in 1..=config.rows {
+        for col in 1..=config.cols {
+            let cell = Cell { row, col };
+            if is_live(bits, cell, config.cols) && cell != exclude && !cell.is_outer(config) {
+                blocks.push(cell);
+            }
         }
     }
+    blocks
+}
+
+/// Live neighbors the mimic could step to from `cell`.
+fn mimic_live_moves(bits: u64, cell: Cell, config: BoardConfig) -> Vec<Cell> {
+    cell.get_neighbors(config)
+        .into_iter()
+        .filter(|&neighbor| is_live(bits, neighbor, config.cols))
+        .collect()
+}
+
+/// Whether the mimic, standing on `cell`, can step off the board this turn.
+fn mimic_can_escape(bits: u64, cell: Cell, config: BoardConfig) -> bool {
+    cell.is_outer(config)
+        && mimic_live_moves(bits, cell, config)
+            .iter()
+            .any(|n| n.is_outer(config))
+}
 
-    fn create_imagine_board(&mut self, removing_cells: &Vec<Cell>) -> Self {
-        let mut new_board = Board {
-            cells: self.cells.clone(),
-            map_live_outer_cells: self.map_live_outer_cells.clone(),
+/// Number of moves for the mimic to reach a cell it can escape from, `from` included.
+fn escape_distance(bits: u64, from: Cell, config: BoardConfig) -> usize {
+    if mimic_can_escape(bits, from, config) {
+        return 0;
+    }
+
+    let mut visited = cell_bit(from.row, from.col, config.cols);
+    let mut frontier = vec![from];
+    let mut distance = 0;
+
+    while !frontier.is_empty() {
+        distance += 1;
+        let mut next_frontier = Vec::new();
+        for cell in frontier {
+            for neighbor in mimic_live_moves(bits, cell, config) {
+                let neighbor_bit = cell_bit(neighbor.row, neighbor.col, config.cols);
+                if visited & neighbor_bit != 0 {
+                    continue;
+                }
+                visited |= neighbor_bit;
+                if mimic_can_escape(bits, neighbor, config) {
+                    return distance;
+                }
+                next_frontier.push(neighbor);
+            }
+        }
+        frontier = next_frontier;
+    }
+
+    usize::MAX
+}
+
+/// The live neighbor the mimic would step to, minimizing escape distance and
+/// breaking ties in `(row, col)` reading order.
+fn mimic_best_move(bits: u64, mimic: Cell, config: BoardConfig) -> Cell {
+    let mut moves = mimic_live_moves(bits, mimic, config);
+    moves.sort_by_key(|c| (c.row, c.col));
+    moves
+        .into_iter()
+        .min_by_key(|&c| escape_distance(bits, c, config))
+        .expect("mimic has no live move to evaluate")
+}
+
+/// `None` while the game is ongoing; `Some(true)` if the mimic escapes, `Some(false)` if it is trapped.
+fn game_outcome(bits: u64, mimic: Cell, config: BoardConfig) -> Option<bool> {
+    if mimic_can_escape(bits, mimic, config) {
+        return Some(true);
+    }
+    if mimic_live_moves(bits, mimic, config)
+        .iter()
+        .all(|n| n.is_outer(config))
+    {
+        return Some(false);
+    }
+    None
+}
+
+/// Memoized (transposition-cached) search over `(mimic_cell, board_bits, config)` for the
+/// shortest removal sequence that guarantees capture, or `None` if the mimic can always
+/// escape. Only the player's side branches; the mimic always replies with the single
+/// greedy move `mimic_best_move` picks, so this isn't minimax/alpha-beta — there's no
+/// minimizing branch to prune.
+#[cached(size = 200000)]
+fn solve_game_state(
+    bits: u64,
+    mimic_row: u8,
+    mimic_col: u8,
+    config: BoardConfig,
+) -> Option<Vec<Cell>> {
+    let mimic = Cell {
+        row: mimic_row,
+        col: mimic_col,
+    };
+
+    if let Some(mimic_escapes) = game_outcome(bits, mimic, config) {
+        return if mimic_escapes { None } else { Some(Vec::new()) };
+    }
+
+    let mut best: Option<Vec<Cell>> = None;
+    for block in available_blocks_excluding(bits, mimic, config) {
+        let remaining_bits = bits & !cell_bit(block.row, block.col, config.cols);
+
+        // The removal itself may already decide the game before the mimic gets to move.
+        let removals = match game_outcome(remaining_bits, mimic, config) {
+            Some(true) => None,
+            Some(false) => Some(Vec::new()),
+            None => {
+                let mimic_move = mimic_best_move(remaining_bits, mimic, config);
+                solve_game_state(remaining_bits, mimic_move.row, mimic_move.col, config)
+            }
         };
 
-        for cell in removing_cells {
-            new_board.drop_cell(cell.row, cell.col);
+        if let Some(mut removals) = removals {
+            removals.insert(0, block);
+            if best.as_ref().is_none_or(|current| removals.len() < current.len()) {
+                best = Some(removals);
+            }
+        }
+    }
+    best
+}
+
+impl Board {
+    fn new(config: BoardConfig) -> Self {
+        Board {
+            bits: full_mask(config),
+            config,
+        }
+    }
+
+    fn drop_cell(&mut self, row: u8, col: u8) {
+        self.bits &= !cell_bit(row, col, self.config.cols);
+    }
+
+    fn create_imagine_board(&self, removing_cells: &Vec<Cell>) -> Self {
+        Board {
+            bits: self.bits & !mask_from_cells(removing_cells, self.config.cols),
+            config: self.config,
         }
-        new_board
     }
 
     fn value_at(&self, row: u8, col: u8) -> bool {
-        self.cells[(row - 1) as usize][(col - 1) as usize]
+        self.bits & cell_bit(row, col, self.config.cols) != 0
     }
 
     fn value_at_cell(&self, cell: &Cell) -> bool {
-        self.cells[(cell.row - 1) as usize][(cell.col - 1) as usize]
+        self.value_at(cell.row, cell.col)
+    }
+
+    fn live_outer_cells(&self) -> Vec<Cell> {
+        cells_from_mask(self.bits & outer_mask(self.config), self.config.cols)
     }
 
-    fn from_input(input: Vec<u8>) -> Self {
-        let mut board = Board::new();
-        board.gen_map_live_outer_cells();
+    fn from_input(input: Vec<u8>, config: BoardConfig) -> Self {
+        let mut board = Board::new(config);
         let mut dropped_cells: Vec<(u8, u8)> = Vec::new();
 
         for &value in input.iter() {
             let col = value / 10;
             let row = value % 10;
-            if !is_valid_location((row, col)) {
+            if !is_valid_location((row, col), config) {
                 panic!("Invalid row or col input");
             }
             dropped_cells.push((row, col));
@@ -140,40 +498,58 @@ impl Board {
         board
     }
 
-    // fn remove_unreachable_blocks(&mut self) {
-    //     let mut removing_cells: Vec<Cell> = Vec::new();
+    /// Parses a board from an ASCII grid of space-separated `0`/`1` tokens, exactly the
+    /// format `Display` emits. Panics on a row/column count mismatch, matching `from_input`'s
+    /// handling of malformed input.
+    fn from_grid_str(input: &str, config: BoardConfig) -> Self {
+        let lines: Vec<&str> = input.lines().filter(|line| !line.trim().is_empty()).collect();
+        if lines.len() != config.rows as usize {
+            panic!("Expected {} rows, found {}", config.rows, lines.len());
+        }
 
-    //     for cell in self.map_live_outer_cells.values() {
-    //         let live_neighbors: Vec<Cell> = cell
-    //             .get_neighbors()
-    //             .iter()
-    //             .filter(|&c| self.value_at_cell(c))
-    //             .cloned()
-    //             .collect();
+        let mut board = Board::new(config);
+        board.bits = 0;
+
+        for (row_index, line) in lines.iter().enumerate() {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.len() != config.cols as usize {
+                panic!(
+                    "Row {} has {} cells, expected {}",
+                    row_index + 1,
+                    tokens.len(),
+                    config.cols
+                );
+            }
 
-    //         if live_neighbors.len() > 1 {
-    //             continue;
-    //         }
+            for (col_index, token) in tokens.iter().enumerate() {
+                let row = (row_index + 1) as u8;
+                let col = (col_index + 1) as u8;
+                match *token {
+                    "1" => board.bits |= cell_bit(row, col, config.cols),
+                    "0" => {}
+                    other => panic!("Invalid cell token {:?}, expected \"0\" or \"1\"", other),
+                }
+            }
+        }
 
-    //         if live_neighbors.len() == 0 {
-    //             removing_cells.push(*cell);
-    //         }
-    //     }
+        board
+    }
 
-    //     for cell in &removing_cells {
-    //         self.drop_cell(cell.row, cell.col);
-    //     }
-    // }
+    /// Renders the board back to the same `0`/`1` grid format `from_grid_str` parses.
+    fn to_grid_str(self) -> String {
+        self.to_string()
+    }
 
     fn remove_redundant_blocks(&mut self) {
         let mut blocks_removed: u8 = u8::MAX;
-        let mut removing_cells: Vec<Cell> = Vec::new();
 
         while blocks_removed != 0 {
             blocks_removed = 0;
-            for cell in self.map_live_outer_cells.values() {
+            let mut removing_cells: Vec<Cell> = Vec::new();
+
+            for cell in self.live_outer_cells() {
                 let live_neighbors: Vec<Cell> = cell
-                    .get_neighbors()
+                    .get_neighbors(self.config)
                     .iter()
                     .filter(|&c| self.value_at_cell(c))
                     .cloned()
@@ -184,81 +560,132 @@ impl Board {
                 }
 
                 if live_neighbors.len() == 0 {
-                    removing_cells.push(*cell);
+                    removing_cells.push(cell);
                     blocks_removed += 1;
                     continue;
                 }
 
                 let neighbor = live_neighbors.first().unwrap();
-                if neighbor.is_outer() {
-                    removing_cells.push(*cell);
+                if neighbor.is_outer(self.config) {
+                    removing_cells.push(cell);
                     blocks_removed += 1;
                 }
             }
             for cell in &removing_cells {
                 self.drop_cell(cell.row, cell.col);
             }
-            removing_cells.clear();
         }
     }
 
-    fn get_available_blocks(&mut self) -> Vec<Cell> {
-        let mut available_blocks: Vec<Cell> = Vec::new();
-        for row in 1..ROWS + 1 {
-            for col in 1..COLS + 1 {
-                let cell = Cell {
-                    row: row as u8,
-                    col: col as u8,
-                };
-                if self.value_at_cell(&cell)
-                    && (row != MIMIC_INITIAL_ROW || col != MIMIC_INITIAL_COL)
-                    && !cell.is_outer()
-                {
-                    available_blocks.push(cell);
+    fn get_available_blocks(&self) -> Vec<Cell> {
+        available_blocks_excluding(
+            self.bits,
+            Cell {
+                row: self.config.mimic_row,
+                col: self.config.mimic_col,
+            },
+            self.config,
+        )
+    }
+
+    /// Shortest sequence of block removals that guarantees capture of the mimic,
+    /// or `None` if the mimic can always escape no matter what the player removes.
+    fn solve_game(&self) -> Option<Vec<Cell>> {
+        solve_game_state(
+            self.bits,
+            self.config.mimic_row,
+            self.config.mimic_col,
+            self.config,
+        )
+    }
+
+    fn calc_benefit(&self, removing_cells: &Vec<Cell>) -> (isize, Vec<Cell>) {
+        let (benefit, total_removing_mask) = calc_benefit_bits(
+            self.bits,
+            mask_from_cells(removing_cells, self.config.cols),
+            self.config,
+        );
+        (benefit, cells_from_mask(total_removing_mask, self.config.cols))
+    }
+
+    /// Dijkstra shortest path for the mimic (at its starting cell) to any live outer cell,
+    /// moving only through live cells. Ties on distance break in `(row, col)` reading order
+    /// so the recovered path is deterministic.
+    fn escape_path(&self) -> Option<(usize, Vec<Cell>)> {
+        let start = Cell {
+            row: self.config.mimic_row,
+            col: self.config.mimic_col,
+        };
+        if !self.value_at_cell(&start) {
+            return None;
+        }
+
+        let mut dist: HashMap<Cell, usize> = HashMap::new();
+        let mut came_from: HashMap<Cell, Cell> = HashMap::new();
+        let mut frontier: BinaryHeap<Reverse<(usize, u8, u8)>> = BinaryHeap::new();
+
+        dist.insert(start, 0);
+        frontier.push(Reverse((0, start.row, start.col)));
+
+        while let Some(Reverse((distance, row, col))) = frontier.pop() {
+            let cell = Cell { row, col };
+            if distance > *dist.get(&cell).unwrap_or(&usize::MAX) {
+                continue;
+            }
+
+            if cell.is_outer(self.config) {
+                return Some((distance, reconstruct_path(&came_from, cell)));
+            }
+
+            for neighbor in cell.get_neighbors(self.config) {
+                if !self.value_at_cell(&neighbor) {
+                    continue;
+                }
+
+                let next_distance = distance + 1;
+                if next_distance < *dist.get(&neighbor).unwrap_or(&usize::MAX) {
+                    dist.insert(neighbor, next_distance);
+                    came_from.insert(neighbor, cell);
+                    frontier.push(Reverse((next_distance, neighbor.row, neighbor.col)));
                 }
             }
         }
-        available_blocks
-    }
-
-    fn calc_benefit(&mut self, removing_cells: &Vec<Cell>) -> (isize, Vec<Cell>) {
-        let mut imaginery_board = self.create_imagine_board(removing_cells);
-        // imaginery_board.remove_unreachable_blocks();
-        imaginery_board.remove_redundant_blocks();
 
-        let reachable_cells: Vec<Cell> = imaginery_board.get_reachable_cells();
-        let border_cells: Vec<Cell> = reachable_cells
-            .iter()
-            .filter(|&&cell| cell.is_outer())
-            .cloned()
-            .collect();
+        None
+    }
 
-        let num_total_removing_cells: usize = border_cells.len() + removing_cells.len();
-        if num_total_removing_cells > MAX_BLOCKS_TO_REMOVE {
-            return (-1, Vec::new());
+    /// Interior blocks worth considering for removal: those reachable by the mimic from
+    /// its current cell. `calc_benefit`'s objective is the size of that reachable region,
+    /// and a block the flood fill never touches can't change it either way — dropping it
+    /// only spends a removal slot for nothing — so cells outside it are never worth a
+    /// combination slot. (Escape *distance* isn't the right yardstick here: a block off
+    /// the shortest escape path can still sit inside the reachable interior and change its
+    /// size, so the prune has to key off reachability, not the path itself.)
+    /// When `escape_path` finds no path, every available block is returned unfiltered.
+    fn candidate_blocks(&self) -> Vec<Cell> {
+        let available_blocks = self.get_available_blocks();
+
+        if self.escape_path().is_none() {
+            return available_blocks;
         }
 
-        let total_removing_cells: Vec<Cell> = border_cells
-            .clone()
+        let reachable = get_reachable_mask(self.bits, self.config);
+        available_blocks
             .into_iter()
-            .chain(removing_cells.clone().into_iter())
-            .collect();
-
-        return (
-            (reachable_cells.len() - border_cells.len()) as isize,
-            total_removing_cells,
-        );
+            .filter(|cell| reachable & cell_bit(cell.row, cell.col, self.config.cols) != 0)
+            .collect()
     }
 
-    fn solve(&mut self) -> (isize, Vec<Vec<Cell>>) {
-        let available_blocks: Vec<Cell> = self.get_available_blocks();
+    fn solve(&self) -> (isize, Vec<Vec<Cell>>) {
+        let available_blocks: Vec<Cell> = self.candidate_blocks();
         println!("Available blocks: {}", available_blocks.iter().join(", "));
 
         let mut map_size_combinations: BTreeMap<u8, Vec<Vec<Cell>>> = BTreeMap::new();
         map_size_combinations.insert(0, vec![vec![]]);
 
+        let max_combination_size = self.config.max_removals.min(available_blocks.len());
         let mut num_combos: usize = 1;
-        for size in 1..11 {
+        for size in 1..=max_combination_size {
             let combos: Vec<Vec<Cell>> = available_blocks
                 .clone()
                 .into_iter()
@@ -322,63 +749,60 @@ impl Board {
         return (max_benefit, max_benefit_combinations);
     }
 
-    fn gen_map_live_outer_cells(&mut self) -> &Self {
-        for row in 1..ROWS + 1 {
-            for col in 1..COLS + 1 {
-                if self.value_at(row as u8, col as u8) && (row % 6 == 1 || col % 6 == 1) {
-                    self.map_live_outer_cells.insert(
-                        (row as u8, col as u8),
-                        Cell {
-                            row: row as u8,
-                            col: col as u8,
-                        },
-                    );
-                }
-            }
+    /// Simulated-annealing alternative to `solve` for boards where the exhaustive
+    /// `combinations` sweep is intractable. Deterministic from `seed`, trading an
+    /// optimality guarantee for tractability over `iters` local-search steps.
+    fn solve_annealing(&self, seed: u64, iters: usize) -> (isize, Vec<Cell>) {
+        let available_blocks = self.get_available_blocks();
+        if available_blocks.is_empty() {
+            return (0, Vec::new());
         }
-        self
-    }
-
-    fn get_reachable_cells(&mut self) -> Vec<Cell> {
-        let mut queue: VecDeque<Cell> = VecDeque::new();
-        let mut visited: HashMap<Cell, bool> = HashMap::new();
-        let mut queued: HashMap<Cell, bool> = HashMap::new();
-
-        queue.push_back(Cell {
-            col: MIMIC_INITIAL_COL as u8,
-            row: MIMIC_INITIAL_ROW as u8,
-        });
-
-        while !queue.is_empty() {
-            let cell = queue.pop_front().unwrap();
-            visited.insert(cell, true);
-            for neighbor_cell in cell.get_neighbors() {
-                if !self.value_at_cell(&neighbor_cell) {
-                    continue;
-                }
-
-                if cell.is_outer() && neighbor_cell.is_outer() {
-                    continue;
-                }
 
-                if visited.contains_key(&neighbor_cell) || queued.contains_key(&neighbor_cell) {
-                    continue;
+        let mut rng = Rng::new(seed);
+
+        // Start from the empty set rather than seeding at `max_removals` cells, which is
+        // usually already over budget and stuck at the infeasible sentinel benefit of -1
+        // before a single move runs. The empty set isn't necessarily feasible either — its
+        // own live border can already exceed `max_removals` — so its benefit still has to
+        // be measured, not assumed.
+        let mut current: Vec<Cell> = Vec::new();
+        let mut current_benefit = self.calc_benefit(&current).0;
+
+        let mut best = current.clone();
+        let mut best_benefit = current_benefit;
+
+        let mut temperature = 1.0f64;
+        for _ in 0..iters {
+            let candidate = propose_neighbor(&current, &available_blocks, &mut rng);
+            let candidate_benefit = self.calc_benefit(&candidate).0;
+
+            let delta = (candidate_benefit - current_benefit) as f64;
+            if delta >= 0.0 || rng.next_f64() < (delta / temperature).exp() {
+                current = candidate;
+                current_benefit = candidate_benefit;
+                if current_benefit > best_benefit {
+                    best_benefit = current_benefit;
+                    best = current.clone();
                 }
+            }
 
-                queue.push_back(neighbor_cell);
-                queued.insert(neighbor_cell, true);
+            temperature *= 0.995;
+            if temperature < 1e-3 {
+                current = best.clone();
+                current_benefit = best_benefit;
+                temperature = 1.0;
             }
         }
 
-        return visited.keys().cloned().collect();
+        (best_benefit, best)
     }
 }
 
 impl fmt::Display for Board {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for row in self.cells.iter() {
-            for &cell in row.iter() {
-                write!(f, "{} ", if cell { "1" } else { "0" })?;
+        for row in 1..=self.config.rows {
+            for col in 1..=self.config.cols {
+                write!(f, "{} ", if self.value_at(row, col) { "1" } else { "0" })?;
             }
             writeln!(f)?;
         }
@@ -402,16 +826,81 @@ fn parse_input() -> Vec<u8> {
         .collect()
 }
 
+fn read_grid_input(config: BoardConfig) -> String {
+    println!(
+        "Enter a {}x{} grid of 0/1 tokens, one row per line:",
+        config.rows, config.cols
+    );
+
+    let mut grid = String::new();
+    for _ in 0..config.rows {
+        let mut line = String::new();
+        io::stdin()
+            .read_line(&mut line)
+            .expect("Failed to read line");
+        grid.push_str(&line);
+    }
+    grid
+}
+
 fn main() {
-    let input: Vec<u8> = parse_input();
-    let mut board = Board::from_input(input);
+    let config = BoardConfig::default();
+    let use_grid_input = std::env::args().any(|arg| arg == "--grid");
+
+    let mut board = if use_grid_input {
+        Board::from_grid_str(&read_grid_input(config), config)
+    } else {
+        Board::from_input(parse_input(), config)
+    };
     board.remove_redundant_blocks();
-    println!("{}", board);
-    println!("Live outer has {} cells", board.map_live_outer_cells.len());
+    print!("{}", board.to_grid_str());
+    println!("Live outer has {} cells", board.live_outer_cells().len());
     let (benefit, combinations) = board.solve();
     println!("The maximum benefit is {}", benefit);
     println!("All combinations:");
     for combination in combinations {
         println!("Cells: {}", combination.iter().join(", "));
     }
+
+    match board.solve_game() {
+        Some(removals) => println!(
+            "Guaranteed capture by removing: {}",
+            removals.iter().join(", ")
+        ),
+        None => println!("The mimic can always escape"),
+    }
+
+    // 2000 iters isn't enough runway to climb out of the all-live board's infeasible
+    // plateau (every set under 6 removals scores the same -1 sentinel); 20_000 is.
+    let (annealing_benefit, annealing_cells) = board.solve_annealing(42, 20_000);
+    println!(
+        "Annealing found benefit {} removing: {}",
+        annealing_benefit,
+        annealing_cells.iter().join(", ")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_str_round_trips() {
+        let config = BoardConfig::default();
+        let mut board = Board::new(config);
+        board.drop_cell(1, 1);
+        board.drop_cell(4, 4);
+
+        let round_tripped = Board::from_grid_str(&board.to_grid_str(), config);
+        assert_eq!(round_tripped, board);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row 2 has 6 cells, expected 7")]
+    fn from_grid_str_rejects_ragged_rows() {
+        let config = BoardConfig::default();
+        let grid = "1 1 1 1 1 1 1\n1 1 1 1 1 1\n1 1 1 1 1 1 1\n1 1 1 1 1 1 1\n\
+                     1 1 1 1 1 1 1\n1 1 1 1 1 1 1\n1 1 1 1 1 1 1";
+        Board::from_grid_str(grid, config);
+    }
 }